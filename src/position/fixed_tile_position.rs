@@ -0,0 +1,156 @@
+use std::fmt;
+
+use super::WorldCoords;
+
+/// A `TilePosition` whose fractional offset is stored as a fixed-point
+/// integer instead of `f32`.
+///
+/// `rel_x`/`rel_y` hold a value `v` representing `v / 2^FRAC_BITS`, always
+/// within `[0, 1)` of a tile. Because the fraction is an integer, equality
+/// is exact (no epsilon, no `floats_equal`) and add/sub never accumulate
+/// rounding noise, so crossings computed from a `FixedTilePosition` are
+/// bit-identical on every target.
+///
+/// `FRAC_BITS` trades resolution for range: it must leave enough headroom
+/// in a `u32` for `2^FRAC_BITS` to stay well under `u32::MAX` so carries
+/// out of `rel_x`/`rel_y` never overflow. `FRAC_BITS = 16` (the usual
+/// choice) gives a resolution of `1 / 65536` of a tile, i.e. sub-pixel
+/// precision for any tile size used in practice.
+///
+/// This is a parallel representation, not a replacement: existing `f32`
+/// based `TilePosition` users are unaffected.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FixedTilePosition<const FRAC_BITS: u32> {
+    pub x: u32,
+    pub y: u32,
+    // Offset from Tile Lower Left, as `rel / 2^FRAC_BITS`, always in `[0, 2^FRAC_BITS)`.
+    pub rel_x: u32,
+    pub rel_y: u32,
+}
+
+/// Signed counterpart produced by subtracting two `FixedTilePosition`s,
+/// mirroring `SignedTilePosition`'s relationship to `TilePosition`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SignedFixedTilePosition<const FRAC_BITS: u32> {
+    pub x: i64,
+    pub y: i64,
+    pub rel_x: u32,
+    pub rel_y: u32,
+}
+
+impl<const FRAC_BITS: u32> FixedTilePosition<FRAC_BITS> {
+    const SCALE: u32 = 1 << FRAC_BITS;
+
+    #[must_use]
+    pub fn new(x: u32, y: u32, rel_x: u32, rel_y: u32) -> Self {
+        debug_assert!(rel_x < Self::SCALE, "rel_x out of [0, 1) range");
+        debug_assert!(rel_y < Self::SCALE, "rel_y out of [0, 1) range");
+        Self { x, y, rel_x, rel_y }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_world_coords(&self, tile_size: f32) -> WorldCoords {
+        let rel_x = self.rel_x as f32 / Self::SCALE as f32;
+        let rel_y = self.rel_y as f32 / Self::SCALE as f32;
+        WorldCoords::new(self.x as f32 + rel_x, self.y as f32 + rel_y, tile_size)
+    }
+
+    fn units_x(&self) -> i64 {
+        i64::from(self.x) * i64::from(Self::SCALE) + i64::from(self.rel_x)
+    }
+
+    fn units_y(&self) -> i64 {
+        i64::from(self.y) * i64::from(Self::SCALE) + i64::from(self.rel_y)
+    }
+}
+
+impl<const FRAC_BITS: u32> SignedFixedTilePosition<FRAC_BITS> {
+    const SCALE: u32 = 1 << FRAC_BITS;
+
+    /// Splits a signed count of fractional units into a tile index plus a
+    /// `[0, SCALE)` remainder, carrying into the index on over/underflow.
+    fn from_units(units_x: i64, units_y: i64) -> Self {
+        let scale = i64::from(Self::SCALE);
+        let (x, rel_x) = (units_x.div_euclid(scale), units_x.rem_euclid(scale));
+        let (y, rel_y) = (units_y.div_euclid(scale), units_y.rem_euclid(scale));
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self { x, y, rel_x: rel_x as u32, rel_y: rel_y as u32 }
+    }
+}
+
+impl<const FRAC_BITS: u32> std::ops::Sub for FixedTilePosition<FRAC_BITS> {
+    type Output = SignedFixedTilePosition<FRAC_BITS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        SignedFixedTilePosition::from_units(
+            self.units_x() - rhs.units_x(),
+            self.units_y() - rhs.units_y(),
+        )
+    }
+}
+
+impl<const FRAC_BITS: u32> std::ops::Add<SignedFixedTilePosition<FRAC_BITS>>
+    for FixedTilePosition<FRAC_BITS>
+{
+    type Output = SignedFixedTilePosition<FRAC_BITS>;
+
+    fn add(self, rhs: SignedFixedTilePosition<FRAC_BITS>) -> Self::Output {
+        SignedFixedTilePosition::from_units(
+            self.units_x() + rhs.x * i64::from(Self::SCALE) + i64::from(rhs.rel_x),
+            self.units_y() + rhs.y * i64::from(Self::SCALE) + i64::from(rhs.rel_y),
+        )
+    }
+}
+
+impl<const FRAC_BITS: u32> fmt::Debug for FixedTilePosition<FRAC_BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FixedTilePosition<{}> {{ x: {}, rel_x: {}/{}, y: {}, rel_y: {}/{} }}",
+            FRAC_BITS, self.x, self.rel_x, Self::SCALE, self.y, self.rel_y, Self::SCALE
+        )
+    }
+}
+
+impl<const FRAC_BITS: u32> fmt::Debug for SignedFixedTilePosition<FRAC_BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = 1u32 << FRAC_BITS;
+        write!(
+            f,
+            "SignedFixedTilePosition<{}> {{ x: {}, rel_x: {}/{}, y: {}, rel_y: {}/{} }}",
+            FRAC_BITS, self.x, self.rel_x, scale, self.y, self.rel_y, scale
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Fixed = FixedTilePosition<16>;
+
+    #[test]
+    fn exact_equality_needs_no_epsilon() {
+        assert_eq!(Fixed::new(1, 1, 32768, 16384), Fixed::new(1, 1, 32768, 16384));
+    }
+
+    #[test]
+    fn subtract_without_carry() {
+        assert_eq!(
+            Fixed::new(2, 3, 0, 0) - Fixed::new(1, 4, 0, 0),
+            SignedFixedTilePosition { x: 1, y: -1, rel_x: 0, rel_y: 0 }
+        );
+    }
+
+    #[test]
+    fn subtract_carries_into_tile_index() {
+        // 0.25 - 0.75 = -0.5, which underflows [0, 1) and must borrow from
+        // the tile index: -0.5 is represented as x: -1, rel_x: 0.5.
+        let quarter = Fixed::SCALE / 4;
+        let three_quarters = Fixed::SCALE / 4 * 3;
+        assert_eq!(
+            Fixed::new(1, 1, quarter, 0) - Fixed::new(1, 1, three_quarters, 0),
+            SignedFixedTilePosition { x: -1, y: 0, rel_x: Fixed::SCALE / 2, rel_y: 0 }
+        );
+    }
+}
@@ -0,0 +1,386 @@
+use std::convert::TryFrom;
+
+use super::{SignedTilePosition, TilePosition, WorldCoords};
+use crate::Crossing;
+
+/// A directed polygon edge, `from -> to`. Vertices must be wound
+/// counter-clockwise so that a point is inside the edge's half-plane when
+/// the 2D cross product `(to - from).det(p - from) >= 0`.
+enum Edge {
+    /// Fast path for a grid-aligned wall: comparing a single coordinate
+    /// against a constant instead of running the full cross product.
+    Horizontal { y: f32, inside_is_above: bool },
+    Vertical { x: f32, inside_is_right: bool },
+    General { from: (f32, f32), to: (f32, f32) },
+}
+
+impl Edge {
+    fn new(from: (f32, f32), to: (f32, f32)) -> Self {
+        if (from.1 - to.1).abs() < f32::EPSILON {
+            // Horizontal edge: CCW winding puts "inside" above the edge
+            // when `to` is to the right of `from` (to.0 > from.0), as for
+            // a bottom edge running left to right.
+            Self::Horizontal { y: from.1, inside_is_above: to.0 > from.0 }
+        } else if (from.0 - to.0).abs() < f32::EPSILON {
+            // CCW winding puts "inside" to the right of the edge when `to`
+            // is below `from` (to.1 < from.1), as for a left edge running
+            // top to bottom.
+            Self::Vertical { x: from.0, inside_is_right: to.1 < from.1 }
+        } else {
+            Self::General { from, to }
+        }
+    }
+
+    /// Signed distance-like value from the half-plane boundary: `>= 0`
+    /// means `p` is on the inside of this edge.
+    fn signed(&self, p: (f32, f32)) -> f32 {
+        match *self {
+            Self::Horizontal { y, inside_is_above } => {
+                let d = p.1 - y;
+                if inside_is_above {
+                    d
+                } else {
+                    -d
+                }
+            }
+            Self::Vertical { x, inside_is_right } => {
+                let d = p.0 - x;
+                if inside_is_right {
+                    d
+                } else {
+                    -d
+                }
+            }
+            Self::General { from, to } => {
+                (to.0 - from.0) * (p.1 - from.1) - (to.1 - from.1) * (p.0 - from.0)
+            }
+        }
+    }
+
+    /// Every variant's `signed` is affine in `p`: `a * p.x + b * p.y + c`.
+    /// The batched/SIMD path below lanes that affine evaluation directly
+    /// instead of matching on the enum per ray.
+    #[cfg(feature = "simd")]
+    fn coeffs(&self) -> (f32, f32, f32) {
+        match *self {
+            Self::Horizontal { y, inside_is_above } => {
+                let sign = if inside_is_above { 1.0 } else { -1.0 };
+                (0.0, sign, -sign * y)
+            }
+            Self::Vertical { x, inside_is_right } => {
+                let sign = if inside_is_right { 1.0 } else { -1.0 };
+                (sign, 0.0, -sign * x)
+            }
+            Self::General { from, to } => {
+                let a = -(to.1 - from.1);
+                let b = to.0 - from.0;
+                let c = (to.1 - from.1) * from.0 - (to.0 - from.0) * from.1;
+                (a, b, c)
+            }
+        }
+    }
+}
+
+/// A convex polygon (room boundary, camera frustum, obstacle hull, ...)
+/// that rays can be clipped against, producing the `TilePosition` where the
+/// ray enters and exits it.
+pub struct ConvexPolygon {
+    edges: Vec<Edge>,
+}
+
+impl ConvexPolygon {
+    /// Builds a polygon from counter-clockwise wound vertices, one edge per
+    /// consecutive pair, wrapping back to the first vertex.
+    #[must_use]
+    pub fn new(vertices: &[WorldCoords]) -> Self {
+        let edges = vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .take(vertices.len())
+            .map(|(from, to)| Edge::new((from.x, from.y), (to.x, to.y)))
+            .collect();
+        Self { edges }
+    }
+
+    /// Clips the ray `from -> to` against this polygon and returns the
+    /// crossing in the same shape the grid-clip `Crossing` uses: `valid` is
+    /// the last point still inside the polygon, `invalid` the first point
+    /// outside of it. Both are `None` when the ray never enters the
+    /// polygon.
+    ///
+    /// `Crossing` only has room for one transition. A ray that both enters
+    /// and exits strictly inside `[0, 1]` (it passes through the polygon
+    /// and ends outside it again) reports the *exit* boundary, since that's
+    /// the one adjacent to `to`; the entry boundary is not represented.
+    /// Clipping again with `to` moved back inside the polygon, before the
+    /// exit, reports the entry boundary.
+    #[must_use]
+    pub fn clip(&self, from: &TilePosition, to: &TilePosition, tile_size: f32) -> Crossing {
+        let origin = from.to_world_coords(tile_size);
+        let target = to.to_world_coords(tile_size);
+
+        let (t_enter, t_exit) = match self.clip_ray((origin.x, origin.y), (target.x, target.y)) {
+            Some(ts) => ts,
+            None => return Crossing { valid: None, invalid: None },
+        };
+
+        let lerp = |t: f32| -> Option<TilePosition> {
+            let x = origin.x + t * (target.x - origin.x);
+            let y = origin.y + t * (target.y - origin.y);
+            let stp: SignedTilePosition = WorldCoords::new(x, y, tile_size).to_signed_tile_position();
+            TilePosition::try_from(stp).ok()
+        };
+
+        // Check the exit first: whenever the ray leaves the polygon before
+        // reaching `to` (`t_exit < 1.0`), that's the boundary nearest the
+        // ray's endpoint and the one worth reporting, even if the ray also
+        // entered partway through (`t_enter > 0.0`) — see the doc comment
+        // above. Only fall back to the entry boundary when the ray stays
+        // inside all the way to `to`.
+        let (invalid_t, valid_t) = if t_exit < 1.0 {
+            (t_exit + f32::EPSILON, t_exit)
+        } else if t_enter > 0.0 {
+            (t_enter - f32::EPSILON, t_enter)
+        } else {
+            return Crossing { valid: lerp(1.0), invalid: None };
+        };
+
+        Crossing { valid: lerp(valid_t), invalid: lerp(invalid_t) }
+    }
+
+    /// Casts many rays sharing one `origin` against this polygon at once,
+    /// e.g. a field-of-view fan or a sensor array, and returns one
+    /// `Crossing` per target in the same order.
+    ///
+    /// With the `simd` feature enabled, rays are processed four at a time
+    /// in `wide::f32x4` lanes instead of falling back to the scalar
+    /// `clip` for each one; the lane count is a portable four-wide
+    /// minimum rather than tuning to one target ISA's widest register.
+    ///
+    /// This clips against a polygon boundary specifically; for casting many
+    /// rays against the grid itself (an arbitrary per-tile validity check
+    /// rather than a fixed shape) see [`TilePosition::cast_many`], which
+    /// batches the DDA grid traversal the same way.
+    ///
+    /// [`TilePosition::cast_many`]: super::TilePosition::cast_many
+    #[must_use]
+    pub fn clip_many(&self, origin: &TilePosition, targets: &[TilePosition], tile_size: f32) -> Vec<Crossing> {
+        #[cfg(feature = "simd")]
+        {
+            simd::clip_many(self, origin, targets, tile_size)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            targets.iter().map(|target| self.clip(origin, target, tile_size)).collect()
+        }
+    }
+
+    /// Finds the nearest entry/exit parameters `t` (each in `[0, 1]`) along
+    /// ray `origin -> target`, or `None` when the ray misses the polygon
+    /// entirely. This is the classic convex-polygon line clip: each edge's
+    /// half-plane test is affine in `t`, so the crossing parameter is found
+    /// by solving the two-line intersection directly instead of stepping.
+    fn clip_ray(&self, origin: (f32, f32), target: (f32, f32)) -> Option<(f32, f32)> {
+        let mut t_enter = 0.0_f32;
+        let mut t_exit = 1.0_f32;
+
+        for edge in &self.edges {
+            let a = edge.signed(origin);
+            let b = edge.signed(target) - a;
+
+            if b.abs() < f32::EPSILON {
+                if a < 0.0 {
+                    return None; // Parallel to this edge and entirely outside it.
+                }
+                continue;
+            }
+
+            let t = -a / b;
+            if b < 0.0 {
+                t_exit = t_exit.min(t);
+            } else {
+                t_enter = t_enter.max(t);
+            }
+
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        Some((t_enter, t_exit))
+    }
+}
+
+/// Lane-wide variant of `ConvexPolygon::clip_ray`/`clip`, processing four
+/// rays at a time. The origin is shared and stays scalar. The per-edge
+/// affine evaluation and the `t_enter`/`t_exit` updates are lane-wide;
+/// each target is converted with the scalar `to_world_coords` before being
+/// packed into lanes, and the final conversion back to `TilePosition` is
+/// done per lane.
+#[cfg(feature = "simd")]
+mod simd {
+    use std::convert::TryFrom;
+
+    use wide::{f32x4, CmpLt};
+
+    use super::{ConvexPolygon, SignedTilePosition, TilePosition};
+    use crate::Crossing;
+
+    pub(super) fn clip_many(
+        polygon: &ConvexPolygon,
+        origin: &TilePosition,
+        targets: &[TilePosition],
+        tile_size: f32,
+    ) -> Vec<Crossing> {
+        let origin = origin.to_world_coords(tile_size);
+        let coeffs: Vec<(f32, f32, f32)> = polygon.edges.iter().map(super::Edge::coeffs).collect();
+
+        let mut out = Vec::with_capacity(targets.len());
+        for chunk in targets.chunks(4) {
+            let world: Vec<_> = chunk.iter().map(|t| t.to_world_coords(tile_size)).collect();
+            let pad = |get: fn(&super::WorldCoords) -> f32| {
+                let mut lanes = [0.0_f32; 4];
+                for (lane, w) in lanes.iter_mut().zip(world.iter()) {
+                    *lane = get(w);
+                }
+                f32x4::from(lanes)
+            };
+            let xs = pad(|w| w.x);
+            let ys = pad(|w| w.y);
+
+            let mut t_enter = f32x4::splat(0.0);
+            let mut t_exit = f32x4::splat(1.0);
+
+            for &(a, b, c) in &coeffs {
+                let origin_val = a * origin.x + b * origin.y + c;
+                let target_val = xs * f32x4::splat(a) + ys * f32x4::splat(b) + f32x4::splat(c);
+                let delta = target_val - f32x4::splat(origin_val);
+
+                let candidate_t = f32x4::splat(-origin_val) / delta;
+                let exiting = delta.cmp_lt(f32x4::splat(0.0));
+                t_exit = exiting.blend(t_exit.min(candidate_t), t_exit);
+                t_enter = (!exiting).blend(t_enter.max(candidate_t), t_enter);
+
+                // A lane parallel to this edge and already outside it can
+                // never enter the polygon; force it permanently invalid
+                // (t_enter > t_exit) rather than threading a separate mask
+                // through the remaining edges.
+                let parallel_outside =
+                    delta.abs().cmp_lt(f32x4::splat(f32::EPSILON)) & f32x4::splat(origin_val).cmp_lt(f32x4::splat(0.0));
+                t_enter = parallel_outside.blend(f32x4::splat(1.0), t_enter);
+                t_exit = parallel_outside.blend(f32x4::splat(0.0), t_exit);
+            }
+
+            let t_enter: [f32; 4] = t_enter.into();
+            let t_exit: [f32; 4] = t_exit.into();
+
+            for (i, world) in world.iter().enumerate() {
+                let lerp = |t: f32| -> Option<TilePosition> {
+                    let x = origin.x + t * (world.x - origin.x);
+                    let y = origin.y + t * (world.y - origin.y);
+                    let stp: SignedTilePosition =
+                        super::WorldCoords::new(x, y, tile_size).to_signed_tile_position();
+                    TilePosition::try_from(stp).ok()
+                };
+
+                // Same precedence as the scalar `clip`: the exit boundary
+                // (nearest `to`) wins whenever the lane's ray leaves the
+                // polygon before reaching it, even if it also entered
+                // partway through; see `ConvexPolygon::clip`'s doc comment.
+                out.push(if t_enter[i] > t_exit[i] {
+                    Crossing { valid: None, invalid: None }
+                } else if t_exit[i] < 1.0 {
+                    Crossing { valid: lerp(t_exit[i]), invalid: lerp(t_exit[i] + f32::EPSILON) }
+                } else if t_enter[i] > 0.0 {
+                    Crossing { valid: lerp(t_enter[i]), invalid: lerp(t_enter[i] - f32::EPSILON) }
+                } else {
+                    Crossing { valid: lerp(1.0), invalid: None }
+                });
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(tile_size: f32) -> ConvexPolygon {
+        ConvexPolygon::new(&[
+            WorldCoords::new(0.0, 0.0, tile_size),
+            WorldCoords::new(4.0, 0.0, tile_size),
+            WorldCoords::new(4.0, 4.0, tile_size),
+            WorldCoords::new(0.0, 4.0, tile_size),
+        ])
+    }
+
+    #[test]
+    fn ray_crossing_axis_aligned_square() {
+        let polygon = square(1.0);
+        let from: TilePosition = ((0, 0.0), (2, 0.0)).into();
+        let to: TilePosition = ((6, 0.0), (2, 0.0)).into();
+        let crossing = polygon.clip(&from, &to, 1.0);
+        // The ray starts inside the square (x=0) and exits through x=4, so
+        // `valid` must be the point on the x=4 boundary and `invalid` the
+        // point just past it (both in tile 4), not the other way around.
+        let valid = crossing.valid.expect("ray starts inside the polygon");
+        let invalid = crossing.invalid.expect("ray exits the polygon before its end");
+        assert_eq!(valid.x, 4);
+        assert_eq!(invalid.x, 4);
+        assert!(valid.rel_x <= invalid.rel_x);
+    }
+
+    #[test]
+    fn clip_many_matches_clip_per_ray() {
+        let polygon = square(1.0);
+        let origin: TilePosition = ((0, 0.0), (2, 0.0)).into();
+        let targets: Vec<TilePosition> = vec![
+            ((6, 0.0), (2, 0.0)).into(),
+            ((5, 0.0), (5, 0.0)).into(),
+            ((6, 0.0), (1, 0.0)).into(),
+        ];
+
+        let batched = polygon.clip_many(&origin, &targets, 1.0);
+        let individually: Vec<_> =
+            targets.iter().map(|target| polygon.clip(&origin, target, 1.0)).collect();
+
+        assert_eq!(batched.len(), individually.len());
+        for (b, i) in batched.iter().zip(individually.iter()) {
+            assert_eq!(b.valid, i.valid);
+            assert_eq!(b.invalid, i.invalid);
+        }
+    }
+
+    #[test]
+    fn ray_passing_through_square_reports_the_exit_not_the_entry() {
+        // A square spanning x in [2, 6], so a ray from x=0 to x=8 enters at
+        // x=2 and exits at x=6: both an entry and an exit fall strictly
+        // inside [0, 1].
+        let polygon = ConvexPolygon::new(&[
+            WorldCoords::new(2.0, 0.0, 1.0),
+            WorldCoords::new(6.0, 0.0, 1.0),
+            WorldCoords::new(6.0, 4.0, 1.0),
+            WorldCoords::new(2.0, 4.0, 1.0),
+        ]);
+        let from: TilePosition = ((0, 0.0), (2, 0.0)).into();
+        let to: TilePosition = ((8, 0.0), (2, 0.0)).into();
+        let crossing = polygon.clip(&from, &to, 1.0);
+        let valid = crossing.valid.expect("ray passes through the polygon");
+        let invalid = crossing.invalid.expect("ray ends outside the polygon");
+        assert_eq!(valid.x, 6);
+        assert_eq!(invalid.x, 6);
+        assert!(valid.rel_x <= invalid.rel_x);
+    }
+
+    #[test]
+    fn ray_entirely_outside_square_misses() {
+        let polygon = square(1.0);
+        let from: TilePosition = ((5, 0.0), (5, 0.0)).into();
+        let to: TilePosition = ((6, 0.0), (5, 0.0)).into();
+        let crossing = polygon.clip(&from, &to, 1.0);
+        assert!(crossing.valid.is_none());
+        assert!(crossing.invalid.is_none());
+    }
+}
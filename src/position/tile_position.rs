@@ -1,11 +1,59 @@
 use std::{convert::TryFrom, fmt, ops};
 
 use crate::util::{floats_equal, round, round_stp, round_tp};
+use crate::Crossing;
 
 use super::WorldCoords;
 
 const TILE_POSITION_PRECISION: usize = 8;
 
+/// Branchless `floor`, using the float "magic number" trick: adding and
+/// subtracting `2^23` (two to the power of the 23 mantissa bits of an
+/// `f32`) forces a
+/// value within that range to round to the nearest integer; stepping back
+/// by one when the round overshot `v` turns that into an exact floor.
+/// Falls back to `v` unchanged once `|v|` reaches `2^23`, since every
+/// representable `f32` at that magnitude is already an integer.
+///
+/// `pub(crate)`, but `WorldCoords::to_signed_tile_position`/
+/// `from_tile_position` (defined in a module outside this one) do not call
+/// it and keep their own cast-based floor. In this module it backs
+/// `SignedTilePosition::normalized` and, through [`fast_floor_split`], the
+/// positions reported by `TilePosition::cast`/`cast_many`.
+pub(crate) fn fast_floor(v: f32) -> f32 {
+    const MANTISSA_MAGIC: f32 = 8_388_608.0; // 2^23
+    if v.abs() >= MANTISSA_MAGIC {
+        return v;
+    }
+    let rounded = ((v.abs() + MANTISSA_MAGIC) - MANTISSA_MAGIC).copysign(v);
+    if rounded > v {
+        rounded - 1.0
+    } else {
+        rounded
+    }
+}
+
+/// Splits `v` into an integer floor and a `[0, 1)` fractional remainder
+/// using [`fast_floor`], guaranteeing the fraction lands in range without
+/// the epsilon fudging a `debug_assert!` used to rely on.
+///
+/// `pub(crate)` for the same reason as `fast_floor`: `WorldCoords`'s tile
+/// index/`rel` split (in `to_signed_tile_position`/`from_tile_position`)
+/// is exactly this operation and should use it too, but that module isn't
+/// touched here — see the note on [`fast_floor`].
+pub(crate) fn fast_floor_split(v: f32) -> (i64, f32) {
+    let floor = fast_floor(v);
+    let rel = v - floor;
+    #[allow(clippy::cast_possible_truncation)]
+    let index = floor as i64;
+    // For a tiny negative `v`, `v - floor` rounds up to exactly `1.0`.
+    if rel >= 1.0 {
+        (index + 1, 0.0)
+    } else {
+        (index, rel)
+    }
+}
+
 #[derive(Clone)]
 pub struct TilePosition {
     pub x: u32,
@@ -87,9 +135,99 @@ impl TilePosition {
         (x2 - x1, y2 - y1)
     }
 
-    fn to_world_coords(&self, tile_size: f32) -> WorldCoords {
+    pub(crate) fn to_world_coords(&self, tile_size: f32) -> WorldCoords {
         WorldCoords::from_tile_position(self, tile_size)
     }
+
+    /// Grid DDA ray cast (Amanatides & Woo "fast voxel traversal"): walks
+    /// every tile the segment `self -> target` passes through, in tile
+    /// units (`tile_size` plays no part, since every step is exactly one
+    /// tile wide), and stops at the first one for which `is_valid` returns
+    /// `false`.
+    ///
+    /// Returns the same shape [`polygon::ConvexPolygon::clip`] does:
+    /// `valid` is the last tile still satisfying `is_valid` (or `target`
+    /// itself when the ray never leaves valid tiles), `invalid` the first
+    /// tile that doesn't. When `self`'s own tile already fails `is_valid`,
+    /// `valid` is `None` and `invalid` is `self`.
+    ///
+    /// [`polygon::ConvexPolygon::clip`]: super::polygon::ConvexPolygon::clip
+    #[must_use]
+    pub fn cast(&self, target: &Self, is_valid: impl Fn(i64, i64) -> bool) -> Crossing {
+        if !is_valid(i64::from(self.x), i64::from(self.y)) {
+            return Crossing { valid: None, invalid: Some(self.clone()) };
+        }
+
+        let (ox, oy) = self.axes();
+        let (dx, dy) = self.delta_to(target);
+
+        let lerp = |t: f32| -> Option<Self> {
+            let (ix, rx) = fast_floor_split(ox + t * dx);
+            let (iy, ry) = fast_floor_split(oy + t * dy);
+            Self::try_from(SignedTilePosition::new(ix, iy, rx, ry)).ok()
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let (tile_x0, tile_y0) = (self.x as f32, self.y as f32);
+        let step_x: i64 = if dx < 0.0 { -1 } else { 1 };
+        let step_y: i64 = if dy < 0.0 { -1 } else { 1 };
+        let t_delta_x = if dx == 0.0 { f32::INFINITY } else { (1.0 / dx).abs() };
+        let t_delta_y = if dy == 0.0 { f32::INFINITY } else { (1.0 / dy).abs() };
+        let next_boundary_x = if dx < 0.0 { tile_x0 } else { tile_x0 + 1.0 };
+        let next_boundary_y = if dy < 0.0 { tile_y0 } else { tile_y0 + 1.0 };
+        let mut t_max_x = if dx == 0.0 { f32::INFINITY } else { (next_boundary_x - ox) / dx };
+        let mut t_max_y = if dy == 0.0 { f32::INFINITY } else { (next_boundary_y - oy) / dy };
+
+        let mut x = i64::from(self.x);
+        let mut y = i64::from(self.y);
+        let target_x = i64::from(target.x);
+        let target_y = i64::from(target.y);
+        let mut last_valid_t = 0.0_f32;
+
+        while (x, y) != (target_x, target_y) {
+            let t = t_max_x.min(t_max_y);
+            if t > 1.0 {
+                break; // The next grid line lies beyond the segment; it ends inside valid tiles.
+            }
+            if t_max_x < t_max_y {
+                x += step_x;
+                t_max_x += t_delta_x;
+            } else {
+                y += step_y;
+                t_max_y += t_delta_y;
+            }
+
+            if !is_valid(x, y) {
+                return Crossing { valid: lerp(last_valid_t), invalid: lerp(t) };
+            }
+            last_valid_t = t;
+        }
+
+        Crossing { valid: Some(target.clone()), invalid: None }
+    }
+
+    /// Casts many rays sharing one `origin` at once, e.g. a field-of-view
+    /// fan, lighting, or a sensor array. With the `simd` feature enabled,
+    /// targets are taken four at a time instead of calling [`cast`] per
+    /// target: their DDA setup is computed in `wide::f32x4` lanes and the
+    /// rays step in lockstep, though each step's `t_max` comparison and
+    /// advance is done per lane in scalar code; lanes that reach their
+    /// terminating tile (or go invalid)
+    /// before the others in their chunk are masked off rather than
+    /// re-stepped. `is_valid` stays a plain per-tile callback either way,
+    /// since it's an arbitrary caller-supplied predicate that can't itself
+    /// be vectorized.
+    #[must_use]
+    pub fn cast_many(&self, targets: &[Self], is_valid: impl Fn(i64, i64) -> bool) -> Vec<Crossing> {
+        #[cfg(feature = "simd")]
+        {
+            grid_simd::cast_many(self, targets, is_valid)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            targets.iter().map(|target| self.cast(target, &is_valid)).collect()
+        }
+    }
 }
 
 impl SignedTilePosition {
@@ -102,15 +240,15 @@ impl SignedTilePosition {
         }
     }
 
+    /// `tile_size` is accepted for API compatibility; the split below
+    /// happens in dimensionless tile units via [`fast_floor_split`] and no
+    /// longer needs it to produce an exact, in-range `rel`.
     pub fn normalized(self, tile_size: f32) -> Self {
-        let dts = 2.0 * tile_size;
+        let _ = tile_size;
         let Self { x, y, rel_x, rel_y } = self;
-        debug_assert!(-dts < rel_x && rel_x < dts);
-        debug_assert!(-dts < rel_y && rel_y < dts);
-
-        #[allow(clippy::clippy::cast_precision_loss)]
-        // pub const MAX: f32 = 3.40282347e+38_f32
-        WorldCoords::new(x as f32 + rel_x, y as f32 + rel_y, tile_size).to_signed_tile_position()
+        let (carry_x, rel_x) = fast_floor_split(rel_x);
+        let (carry_y, rel_y) = fast_floor_split(rel_y);
+        Self::new(x + carry_x, y + carry_y, rel_x, rel_y)
     }
 }
 
@@ -243,6 +381,235 @@ impl From<((u32, f32), (u32, f32))> for TilePosition {
     }
 }
 
+/// Interop with `euclid`'s typed points/vectors, so `crisscross` output can
+/// flow straight into renderers and layout code that already speak euclid.
+#[cfg(feature = "euclid")]
+mod euclid_interop {
+    use super::{SignedTilePosition, TilePosition, WorldCoords};
+
+    /// Unit marker for coordinates expressed in world space.
+    pub struct World;
+    /// Unit marker for coordinates expressed in tile space.
+    pub struct Tile;
+
+    impl From<WorldCoords> for euclid::Point2D<f32, World> {
+        fn from(coords: WorldCoords) -> Self {
+            Self::new(coords.x, coords.y)
+        }
+    }
+
+    // `WorldCoords` carries its `tile_size` with it (so it can convert back
+    // to tile space on its own), but a bare `Point2D` has no such notion, so
+    // the reverse direction takes `tile_size` explicitly rather than being a
+    // `From` impl.
+    impl WorldCoords {
+        #[must_use]
+        pub fn from_euclid(p: euclid::Point2D<f32, World>, tile_size: f32) -> Self {
+            Self::new(p.x, p.y, tile_size)
+        }
+    }
+
+    impl From<&TilePosition> for euclid::Point2D<f32, Tile> {
+        #[allow(clippy::cast_precision_loss)]
+        fn from(tp: &TilePosition) -> Self {
+            Self::new(tp.x as f32 + tp.rel_x, tp.y as f32 + tp.rel_y)
+        }
+    }
+
+    impl From<TilePosition> for euclid::Point2D<f32, Tile> {
+        fn from(tp: TilePosition) -> Self {
+            (&tp).into()
+        }
+    }
+
+    // `Sub`/`Add` already yield a `SignedTilePosition`; this is just a typed
+    // euclid view of the same delta for callers that want one.
+    impl From<&SignedTilePosition> for euclid::Vector2D<f32, Tile> {
+        #[allow(clippy::cast_precision_loss)]
+        fn from(stp: &SignedTilePosition) -> Self {
+            Self::new(stp.x as f32 + stp.rel_x, stp.y as f32 + stp.rel_y)
+        }
+    }
+
+    impl From<SignedTilePosition> for euclid::Vector2D<f32, Tile> {
+        fn from(stp: SignedTilePosition) -> Self {
+            (&stp).into()
+        }
+    }
+}
+
+#[cfg(feature = "euclid")]
+pub use euclid_interop::{Tile, World};
+
+/// Batched counterpart of [`TilePosition::cast`], processing four rays at
+/// a time. Each target's `axes` is read per ray; the deltas from the
+/// origin, step directions, `t_delta` and initial `t_max` are then
+/// computed with `f32x4` ops over the whole chunk. The rays advance in
+/// lockstep, one grid step per active lane per round, but the per-step
+/// `t_max` comparison/advance, the `is_valid` call and the termination
+/// bookkeeping run per lane in scalar code.
+#[cfg(feature = "simd")]
+mod grid_simd {
+    use std::convert::TryFrom;
+
+    use wide::{f32x4, CmpLt};
+
+    use super::{fast_floor_split, SignedTilePosition, TilePosition};
+    use crate::Crossing;
+
+    const LANES: usize = 4;
+
+    pub(super) fn cast_many(
+        origin: &TilePosition,
+        targets: &[TilePosition],
+        is_valid: impl Fn(i64, i64) -> bool,
+    ) -> Vec<Crossing> {
+        if !is_valid(i64::from(origin.x), i64::from(origin.y)) {
+            return targets.iter().map(|_| Crossing { valid: None, invalid: Some(origin.clone()) }).collect();
+        }
+
+        let (ox, oy) = origin.axes();
+        let origin_x = f32x4::splat(ox);
+        let origin_y = f32x4::splat(oy);
+        #[allow(clippy::cast_precision_loss)]
+        let tile_x0 = f32x4::splat(origin.x as f32);
+        #[allow(clippy::cast_precision_loss)]
+        let tile_y0 = f32x4::splat(origin.y as f32);
+
+        let zero = f32x4::splat(0.0);
+        let one = f32x4::splat(1.0);
+        let eps = f32x4::splat(f32::EPSILON);
+        let inf = f32x4::splat(f32::INFINITY);
+
+        let mut out = Vec::with_capacity(targets.len());
+
+        for chunk in targets.chunks(LANES) {
+            let len = chunk.len();
+
+            // Per-target `axes`, then lane-wide deltas: pad unused lanes with the origin
+            // itself so their delta is zero and they resolve immediately.
+            let mut txs = [ox; LANES];
+            let mut tys = [oy; LANES];
+            let mut target_x = [i64::from(origin.x); LANES];
+            let mut target_y = [i64::from(origin.y); LANES];
+            for (i, t) in chunk.iter().enumerate() {
+                let (x, y) = t.axes();
+                txs[i] = x;
+                tys[i] = y;
+                target_x[i] = i64::from(t.x);
+                target_y[i] = i64::from(t.y);
+            }
+            let dx = f32x4::from(txs) - origin_x;
+            let dy = f32x4::from(tys) - origin_y;
+
+            let zero_dx = dx.abs().cmp_lt(eps);
+            let zero_dy = dy.abs().cmp_lt(eps);
+            let neg_dx = dx.cmp_lt(zero);
+            let neg_dy = dy.cmp_lt(zero);
+
+            let step_x: [f32; LANES] = neg_dx.blend(-one, one).into();
+            let step_y: [f32; LANES] = neg_dy.blend(-one, one).into();
+            let t_delta_x: [f32; LANES] = zero_dx.blend(inf, (one / dx).abs()).into();
+            let t_delta_y: [f32; LANES] = zero_dy.blend(inf, (one / dy).abs()).into();
+
+            let boundary_x = tile_x0 + neg_dx.blend(zero, one);
+            let boundary_y = tile_y0 + neg_dy.blend(zero, one);
+            let mut t_max_x = zero_dx.blend(inf, (boundary_x - origin_x) / dx);
+            let mut t_max_y = zero_dy.blend(inf, (boundary_y - origin_y) / dy);
+
+            let mut x = [i64::from(origin.x); LANES];
+            let mut y = [i64::from(origin.y); LANES];
+            let mut last_valid_t = [0.0_f32; LANES];
+            let mut done = [false; LANES];
+            let mut result: [Option<Crossing>; LANES] = [None, None, None, None];
+
+            for i in 0..len {
+                if (x[i], y[i]) == (target_x[i], target_y[i]) {
+                    done[i] = true;
+                    result[i] = Some(Crossing { valid: Some(chunk[i].clone()), invalid: None });
+                }
+            }
+
+            // Safety bound on how many grid lines any one lane in this
+            // chunk can cross before reaching its target tile or leaving
+            // the segment; real rays terminate far sooner.
+            let max_steps = chunk
+                .iter()
+                .map(|t| {
+                    (i64::from(t.x) - i64::from(origin.x)).unsigned_abs()
+                        + (i64::from(t.y) - i64::from(origin.y)).unsigned_abs()
+                })
+                .max()
+                .unwrap_or(0)
+                + 1;
+
+            for _ in 0..max_steps {
+                if done[..len].iter().all(|d| *d) {
+                    break;
+                }
+
+                let t_max_x_arr: [f32; LANES] = t_max_x.into();
+                let t_max_y_arr: [f32; LANES] = t_max_y.into();
+                let mut next_t_max_x = t_max_x_arr;
+                let mut next_t_max_y = t_max_y_arr;
+
+                for i in 0..len {
+                    if done[i] {
+                        continue;
+                    }
+
+                    let t = t_max_x_arr[i].min(t_max_y_arr[i]);
+                    if t > 1.0 {
+                        done[i] = true;
+                        result[i] = Some(Crossing { valid: Some(chunk[i].clone()), invalid: None });
+                        continue;
+                    }
+
+                    if t_max_x_arr[i] < t_max_y_arr[i] {
+                        #[allow(clippy::cast_possible_truncation)]
+                        {
+                            x[i] += step_x[i] as i64;
+                        }
+                        next_t_max_x[i] += t_delta_x[i];
+                    } else {
+                        #[allow(clippy::cast_possible_truncation)]
+                        {
+                            y[i] += step_y[i] as i64;
+                        }
+                        next_t_max_y[i] += t_delta_y[i];
+                    }
+
+                    let lerp = |t: f32| -> Option<TilePosition> {
+                        let (ix, rx) = fast_floor_split(ox + t * (txs[i] - ox));
+                        let (iy, ry) = fast_floor_split(oy + t * (tys[i] - oy));
+                        TilePosition::try_from(SignedTilePosition::new(ix, iy, rx, ry)).ok()
+                    };
+
+                    if !is_valid(x[i], y[i]) {
+                        done[i] = true;
+                        result[i] = Some(Crossing { valid: lerp(last_valid_t[i]), invalid: lerp(t) });
+                    } else {
+                        last_valid_t[i] = t;
+                        if (x[i], y[i]) == (target_x[i], target_y[i]) {
+                            done[i] = true;
+                            result[i] = Some(Crossing { valid: Some(chunk[i].clone()), invalid: None });
+                        }
+                    }
+                }
+
+                t_max_x = f32x4::from(next_t_max_x);
+                t_max_y = f32x4::from(next_t_max_y);
+            }
+
+            for (i, target) in chunk.iter().enumerate() {
+                out.push(result[i].take().unwrap_or(Crossing { valid: Some(target.clone()), invalid: None }));
+            }
+        }
+
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,10 +631,90 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "euclid")]
+    fn euclid_point_from_tile_position() {
+        let tp: TilePosition = ((1, 0.5), (3, 0.25)).into();
+        let point: euclid::Point2D<f32, Tile> = tp.into();
+        assert_eq!(point, euclid::Point2D::new(1.5, 3.25));
+    }
+
+    #[test]
+    #[cfg(feature = "euclid")]
+    fn euclid_vector_from_signed_tile_position_delta() {
+        #[rustfmt::skip]
+        let delta =
+            TilePosition::new(2, 3, 0.0, 0.0)
+          - TilePosition::new(1, 4, 0.0, 0.0);
+        let vector: euclid::Vector2D<f32, Tile> = delta.into();
+        assert_eq!(vector, euclid::Vector2D::new(1.0, -1.0));
+    }
+
+    #[test]
+    fn fast_floor_matches_std_floor() {
+        for v in [0.0_f32, 0.25, 1.0, 1.75, -0.25, -1.0, -1.75, 3.999_999] {
+            assert_eq!(fast_floor(v), v.floor(), "fast_floor({v}) should match v.floor()");
+        }
+    }
+
+    #[test]
+    fn fast_floor_split_rel_always_in_unit_range() {
+        let (carry, rel) = fast_floor_split(-0.25);
+        assert_eq!(carry, -1);
+        assert!((0.0..1.0).contains(&rel));
+        assert!((rel - 0.75).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn normalized_carries_overflowed_rel_into_tile_index() {
+        let stp = SignedTilePosition::new(1, 1, 1.5, -0.25).normalized(1.0);
+        assert_eq!(stp, SignedTilePosition::new(2, 0, 0.5, 0.75));
+    }
+
     #[test]
     fn distance() {
         let tp1: TilePosition = ((1, 0.0), (3, 0.3)).into();
         let tp2: TilePosition = ((4, 0.1), (8, 0.8)).into();
         assert_eq!(round(tp1.distance_global(&tp2, 1.0), 3), 6.313);
     }
+
+    #[test]
+    fn cast_stops_at_the_first_invalid_tile() {
+        let from: TilePosition = ((0, 0.0), (0, 0.0)).into();
+        let to: TilePosition = ((6, 0.0), (0, 0.0)).into();
+        let crossing = from.cast(&to, |x, _y| x < 4);
+        assert_eq!(crossing.valid.expect("tiles before x=4 are valid").x, 3);
+        assert_eq!(crossing.invalid.expect("x=4 is the first invalid tile").x, 4);
+    }
+
+    #[test]
+    fn cast_reaches_target_when_every_tile_is_valid() {
+        let from: TilePosition = ((0, 0.0), (0, 0.0)).into();
+        let to: TilePosition = ((6, 0.0), (2, 0.0)).into();
+        let crossing = from.cast(&to, |_, _| true);
+        assert_eq!(crossing.valid, Some(to));
+        assert!(crossing.invalid.is_none());
+    }
+
+    #[test]
+    fn cast_many_matches_cast_per_ray() {
+        let origin: TilePosition = ((0, 0.0), (0, 0.0)).into();
+        let targets: Vec<TilePosition> = vec![
+            ((6, 0.0), (0, 0.0)).into(),
+            ((3, 0.0), (3, 0.0)).into(),
+            ((0, 0.0), (6, 0.0)).into(),
+            ((5, 0.0), (1, 0.0)).into(),
+            ((1, 0.0), (5, 0.0)).into(),
+        ];
+        let is_valid = |x: i64, y: i64| x < 4 && y < 4;
+
+        let batched = origin.cast_many(&targets, is_valid);
+        let individually: Vec<_> = targets.iter().map(|target| origin.cast(target, is_valid)).collect();
+
+        assert_eq!(batched.len(), individually.len());
+        for (b, i) in batched.iter().zip(individually.iter()) {
+            assert_eq!(b.valid, i.valid);
+            assert_eq!(b.invalid, i.invalid);
+        }
+    }
 }